@@ -0,0 +1,48 @@
+use sdl2::pixels::Color;
+
+use crate::clamp;
+
+pub fn lerp_color(a: Color, b: Color, t: f64) -> Color {
+    let t = clamp(t, 0.0, 1.0);
+    let lerp_channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    Color::RGBA(
+        lerp_channel(a.r, b.r),
+        lerp_channel(a.g, b.g),
+        lerp_channel(a.b, b.b),
+        lerp_channel(a.a, b.a),
+    )
+}
+
+/// Builds a [`Color`] from hue/saturation/brightness, each in `0.0..=1.0`,
+/// plus an alpha channel.
+pub fn hsb(h: f64, s: f64, b: f64, a: u8) -> Color {
+    let h = clamp(h, 0.0, 1.0) * 6.0;
+    let s = clamp(s, 0.0, 1.0);
+    let v = clamp(b, 0.0, 1.0);
+
+    let i = h.floor() as i32;
+    let f = h - h.floor();
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - s * f);
+    let t = v * (1.0 - s * (1.0 - f));
+
+    let (r, g, b) = match i.rem_euclid(6) {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+
+    Color::RGBA(
+        (r * 255.0).round() as u8,
+        (g * 255.0).round() as u8,
+        (b * 255.0).round() as u8,
+        a,
+    )
+}
+
+pub fn with_alpha(color: Color, a: u8) -> Color {
+    Color::RGBA(color.r, color.g, color.b, a)
+}
@@ -0,0 +1,83 @@
+use sdl2::pixels::Color;
+use sdl2::pixels::PixelFormatEnum;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::render::RenderTarget;
+use sdl2::render::Texture;
+use sdl2::render::TextureCreator;
+
+use crate::SdlError;
+
+pub struct Framebuffer<'t> {
+    texture: Texture<'t>,
+    pixels: Vec<u8>,
+    width: u32,
+    height: u32,
+    pixel_size: u32,
+}
+
+impl<'t> Framebuffer<'t> {
+    pub fn new<T>(
+        texture_creator: &'t TextureCreator<T>,
+        width: u32,
+        height: u32,
+        pixel_size: u32,
+    ) -> Result<Self, SdlError> {
+        let texture =
+            texture_creator.create_texture_streaming(PixelFormatEnum::RGB24, width, height)?;
+        Ok(Self {
+            texture,
+            pixels: vec![0; (width * height * 3) as usize],
+            width,
+            height,
+            pixel_size,
+        })
+    }
+
+    pub fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let offset = ((y * self.width + x) * 3) as usize;
+        self.pixels[offset] = color.r;
+        self.pixels[offset + 1] = color.g;
+        self.pixels[offset + 2] = color.b;
+    }
+
+    pub fn clear(&mut self, color: Color) {
+        for chunk in self.pixels.chunks_exact_mut(3) {
+            chunk[0] = color.r;
+            chunk[1] = color.g;
+            chunk[2] = color.b;
+        }
+    }
+
+    /// Uploads the buffer and blits it at `dst`'s origin, scaled up by
+    /// `pixel_size`; `dst`'s width/height are ignored.
+    pub fn present<RT: RenderTarget>(
+        &mut self,
+        canvas: &mut Canvas<RT>,
+        dst: Rect,
+    ) -> Result<(), SdlError> {
+        let pitch = (self.width * 3) as usize;
+        self.texture
+            .with_lock(None, |buffer: &mut [u8], buffer_pitch: usize| {
+                for row in 0..self.height as usize {
+                    let src = &self.pixels[row * pitch..(row + 1) * pitch];
+                    let dst_row = &mut buffer[row * buffer_pitch..row * buffer_pitch + pitch];
+                    dst_row.copy_from_slice(src);
+                }
+            })
+            .map_err(SdlError::LockTexture)?;
+        let pixel_size = self.pixel_size.max(1);
+        let dst = Rect::new(
+            dst.x(),
+            dst.y(),
+            self.width * pixel_size,
+            self.height * pixel_size,
+        );
+        canvas
+            .copy(&self.texture, None, Some(dst))
+            .map_err(SdlError::Draw)
+    }
+}
@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use sdl2::image::LoadTexture;
+use sdl2::image::Sdl2ImageContext;
+use sdl2::render::Texture;
+use sdl2::render::TextureCreator;
+
+use crate::SdlError;
+
+pub fn init_sdl_image() -> Result<Sdl2ImageContext, SdlError> {
+    sdl2::image::init(sdl2::image::InitFlag::PNG | sdl2::image::InitFlag::JPG)
+        .map_err(SdlError::InitImage)
+}
+
+pub fn load_texture<T>(
+    texture_creator: &TextureCreator<T>,
+    path: impl AsRef<Path>,
+) -> Result<Texture<'_>, SdlError> {
+    texture_creator
+        .load_texture(path)
+        .map_err(SdlError::LoadImage)
+}
@@ -5,12 +5,17 @@ use sdl2::event::Event;
 use sdl2::gfx::primitives::DrawRenderer;
 use sdl2::pixels::Color;
 use sdl2::render::BlendMode;
+use sdl2::rect::Rect;
 use sdl2::render::Canvas;
 use sdl2::render::RenderTarget;
+use sdl2::render::Texture;
 use sdl2::render::TextureValueError;
 use sdl2::video::WindowBuildError;
 use sdl2::IntegerOrSdlError;
 
+pub mod color;
+pub mod framebuffer;
+pub mod image;
 pub mod matrix_stack;
 pub mod timer;
 
@@ -52,6 +57,9 @@ pub trait CanvasExt {
     fn reset_matrix(&mut self);
     fn translate(&mut self, dx: f64, dy: f64);
     fn rotate(&mut self, radians: f64);
+    fn scale(&mut self, sx: f64, sy: f64);
+    fn shear_x(&mut self, radians: f64);
+    fn shear_y(&mut self, radians: f64);
     fn push_matrix(&mut self);
     fn pop_matrix(&mut self);
     fn ext_draw_line(
@@ -66,6 +74,13 @@ pub trait CanvasExt {
         radius: f64,
         color: Color,
     ) -> Result<(), SdlError>;
+    fn ext_copy_texture(&mut self, texture: &Texture, dst: Rect) -> Result<(), SdlError>;
+    fn current_matrix(&self) -> nalgebra::Matrix3<f64>;
+    fn transform_point(&self, point: &Point<f64, nalgebra::U2>) -> Point<f64, nalgebra::U2>;
+    fn inverse_transform_point(
+        &self,
+        point: &Point<f64, nalgebra::U2>,
+    ) -> Option<Point<f64, nalgebra::U2>>;
 }
 
 impl<RT: RenderTarget> CanvasExt for Canvas<RT> {
@@ -89,6 +104,23 @@ impl<RT: RenderTarget> CanvasExt for Canvas<RT> {
             nalgebra::geometry::Rotation::from_axis_angle(&nalgebra::Vector3::z_axis(), radians)
     }
 
+    fn scale(&mut self, sx: f64, sy: f64) {
+        *MATRIX_STACK.write().last_mut().unwrap() *=
+            nalgebra::Matrix3::new_nonuniform_scaling(&Vector2::new(sx, sy));
+    }
+
+    fn shear_x(&mut self, radians: f64) {
+        let mut shear = nalgebra::Matrix3::identity();
+        shear[(0, 1)] = radians.tan();
+        *MATRIX_STACK.write().last_mut().unwrap() *= shear;
+    }
+
+    fn shear_y(&mut self, radians: f64) {
+        let mut shear = nalgebra::Matrix3::identity();
+        shear[(1, 0)] = radians.tan();
+        *MATRIX_STACK.write().last_mut().unwrap() *= shear;
+    }
+
     fn push_matrix(&mut self) {
         let top = MATRIX_STACK.read().last().unwrap().clone();
         MATRIX_STACK.write().push(top);
@@ -122,10 +154,50 @@ impl<RT: RenderTarget> CanvasExt for Canvas<RT> {
         radius: f64,
         color: Color,
     ) -> Result<(), SdlError> {
-        let center = MATRIX_STACK.read().last().unwrap().transform_point(center);
+        let matrix = MATRIX_STACK.read().last().unwrap().clone();
+        let center = matrix.transform_point(center);
+        let sx = (matrix[(0, 0)].powi(2) + matrix[(1, 0)].powi(2)).sqrt();
+        let sy = (matrix[(0, 1)].powi(2) + matrix[(1, 1)].powi(2)).sqrt();
+        let radius = radius * (sx + sy) / 2.0;
         self.filled_circle(center.x as i16, center.y as i16, radius as i16, color)
             .map_err(SdlError::Draw)
     }
+
+    fn ext_copy_texture(&mut self, texture: &Texture, dst: Rect) -> Result<(), SdlError> {
+        let matrix = MATRIX_STACK.read().last().unwrap().clone();
+        let angle = matrix[(1, 0)].atan2(matrix[(0, 0)]).to_degrees();
+        let sx = (matrix[(0, 0)].powi(2) + matrix[(1, 0)].powi(2)).sqrt();
+        let sy = (matrix[(0, 1)].powi(2) + matrix[(1, 1)].powi(2)).sqrt();
+        let origin = matrix.transform_point(&Point::new(dst.x() as f64, dst.y() as f64));
+        let dst = Rect::new(
+            origin.x as i32,
+            origin.y as i32,
+            (dst.width() as f64 * sx) as u32,
+            (dst.height() as f64 * sy) as u32,
+        );
+        self.copy_ex(texture, None, Some(dst), angle, None, false, false)
+            .map_err(SdlError::Draw)
+    }
+
+    fn current_matrix(&self) -> nalgebra::Matrix3<f64> {
+        MATRIX_STACK.read().last().unwrap().clone()
+    }
+
+    fn transform_point(&self, point: &Point<f64, nalgebra::U2>) -> Point<f64, nalgebra::U2> {
+        MATRIX_STACK.read().last().unwrap().transform_point(point)
+    }
+
+    fn inverse_transform_point(
+        &self,
+        point: &Point<f64, nalgebra::U2>,
+    ) -> Option<Point<f64, nalgebra::U2>> {
+        MATRIX_STACK
+            .read()
+            .last()
+            .unwrap()
+            .try_inverse()
+            .map(|inverse| inverse.transform_point(point))
+    }
 }
 
 pub fn init_sdl(
@@ -22,3 +22,62 @@ impl Timer {
         }
     }
 }
+
+/// Drives a fixed-timestep game loop, using the same `chrono::Local`
+/// wall-clock as [`Timer`].
+///
+/// Each call to [`FrameLimiter::frame`] measures how much time has passed
+/// since the previous call, accumulates it, and yields the number of fixed
+/// `dt`-sized update steps that should run this frame. Accumulated time is
+/// clamped so a long stall (e.g. a breakpoint) can't cause a spiral of death.
+/// After running the returned number of updates, call [`FrameLimiter::sleep_remainder`]
+/// to give back the rest of the frame budget to the OS, and use
+/// [`FrameLimiter::alpha`] to interpolate rendering between the last two
+/// update steps.
+pub struct FrameLimiter {
+    last_frame: chrono::DateTime<chrono::Local>,
+    accumulator: f64,
+    dt: f64,
+    max_accumulator: f64,
+}
+
+impl FrameLimiter {
+    pub fn new(fps: f64) -> Self {
+        assert!(fps > 0.0, "FrameLimiter fps must be positive, got {fps}");
+        let dt = 1.0 / fps;
+        Self {
+            last_frame: chrono::Local::now(),
+            accumulator: 0.0,
+            dt,
+            max_accumulator: dt * 8.0,
+        }
+    }
+
+    /// Measures elapsed time since the last call and returns the number of
+    /// fixed `dt` update steps to run this frame.
+    pub fn frame(&mut self) -> u32 {
+        let now = chrono::Local::now();
+        let elapsed = (now - self.last_frame).num_nanoseconds().unwrap() as f64 * 1e-9;
+        self.last_frame = now;
+        self.accumulator = (self.accumulator + elapsed).min(self.max_accumulator);
+        let steps = (self.accumulator / self.dt) as u32;
+        self.accumulator -= steps as f64 * self.dt;
+        steps
+    }
+
+    /// Sleeps for whatever remains of the current frame's time budget.
+    pub fn sleep_remainder(&self) {
+        let now = chrono::Local::now();
+        let elapsed = (now - self.last_frame).num_nanoseconds().unwrap() as f64 * 1e-9;
+        let remaining = self.dt - elapsed;
+        if remaining > 0.0 {
+            std::thread::sleep(std::time::Duration::from_secs_f64(remaining));
+        }
+    }
+
+    /// Fractional leftover time in the accumulator, for interpolating render
+    /// state between the last two update steps.
+    pub fn alpha(&self) -> f64 {
+        self.accumulator / self.dt
+    }
+}